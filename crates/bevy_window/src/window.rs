@@ -1,4 +1,4 @@
-use bevy_math::Vec2;
+use bevy_math::{IVec2, Vec2};
 use bevy_utils::Uuid;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -52,15 +52,22 @@ pub struct Window {
     id: WindowId,
     requested_width: f32,
     requested_height: f32,
+    requested_min_width: Option<f32>,
+    requested_min_height: Option<f32>,
+    requested_max_width: Option<f32>,
+    requested_max_height: Option<f32>,
     physical_width: u32,
     physical_height: u32,
+    physical_position: Option<IVec2>,
     scale_factor: f64,
+    scale_factor_override: Option<f64>,
     title: String,
     vsync: bool,
     resizable: bool,
     decorations: bool,
     cursor_visible: bool,
     cursor_locked: bool,
+    cursor_icon: CursorIcon,
     cursor_position: Option<Vec2>,
     mode: WindowMode,
     #[cfg(target_arch = "wasm32")]
@@ -101,18 +108,116 @@ pub enum WindowCommand {
     SetMaximized {
         maximized: bool,
     },
+    SetMinResolution {
+        min_resolution: (Option<f32>, Option<f32>),
+    },
+    SetMaxResolution {
+        max_resolution: (Option<f32>, Option<f32>),
+    },
+    SetPosition {
+        position: IVec2,
+    },
+    SetPositionCentered {
+        monitor: MonitorSelection,
+    },
+    SetCursorIcon {
+        icon: CursorIcon,
+    },
+    RequestUserAttention {
+        request: Option<UserAttentionType>,
+    },
+    SetScaleFactor {
+        scale_factor: f64,
+    },
 }
 
-/// Defines the way a window is displayed
-/// The use_size option that is used in the Fullscreen variant
-/// defines whether a videomode is chosen that best fits the width and height
-/// in the Window structure, or if these are ignored.
-/// E.g. when use_size is set to false the best video mode possible is chosen.
+/// Defines the way a window is displayed.
+///
+/// The `monitor` and `video_mode` fields of the `Fullscreen` variant let the
+/// requester pick which monitor to go fullscreen on, and optionally request
+/// an exclusive [`VideoMode`] (resolution, refresh rate, and bit depth) on
+/// that monitor. When `video_mode` is `None`, the backend picks a "best fit"
+/// mode for the window's current size.
 #[derive(Debug, Clone, Copy)]
 pub enum WindowMode {
     Windowed,
     BorderlessFullscreen,
-    Fullscreen { use_size: bool },
+    Fullscreen {
+        monitor: MonitorSelection,
+        video_mode: Option<VideoMode>,
+    },
+}
+
+/// Selects a monitor: explicitly by its index in the [`Monitors`] resource,
+/// as the operating system's primary monitor, or implicitly as the monitor
+/// the window currently sits on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonitorSelection {
+    /// The monitor the window is currently on.
+    Current,
+    /// The primary monitor, as reported by the operating system.
+    Primary,
+    /// The monitor at the given index in [`Monitors`].
+    Number(usize),
+}
+
+/// A single display mode a [`Monitor`] can be driven at: a resolution,
+/// a refresh rate, and a color bit depth.
+///
+/// `VideoMode`s are reported by the windowing backend through the
+/// [`Monitors`] resource, and can be fed back into [`WindowMode::Fullscreen`]
+/// to request an exclusive fullscreen mode.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VideoMode {
+    /// The index, in [`Monitors`], of the monitor this mode belongs to.
+    pub monitor: usize,
+    /// The resolution of this video mode, in physical pixels.
+    pub resolution: (u32, u32),
+    /// The refresh rate of this video mode, in hertz.
+    pub refresh_rate: u16,
+    /// The number of bits used to represent a single color channel, or 0 if
+    /// unavailable/unknown.
+    pub bit_depth: u16,
+}
+
+/// Information about a monitor connected to the system, including its name,
+/// physical size, scale factor, and the [`VideoMode`]s it can be driven at.
+#[derive(Debug, Clone)]
+pub struct Monitor {
+    /// A human-readable name for this monitor, if the backend can provide one.
+    pub name: Option<String>,
+    /// The physical size of the monitor, in physical pixels.
+    pub physical_size: (u32, u32),
+    /// The scale factor the operating system applies to this monitor.
+    pub scale_factor: f64,
+    /// The [`VideoMode`]s this monitor can be driven at.
+    pub video_modes: Vec<VideoMode>,
+}
+
+/// A resource containing the [`Monitor`]s known to the windowing backend.
+///
+/// This is populated (and refreshed, if the set of connected monitors
+/// changes) by the windowing backend.
+#[derive(Debug, Clone, Default)]
+pub struct Monitors {
+    monitors: Vec<Monitor>,
+}
+
+impl Monitors {
+    /// Iterate over the known monitors, in backend-reported order.
+    pub fn iter(&self) -> impl Iterator<Item = &Monitor> {
+        self.monitors.iter()
+    }
+
+    /// Get the monitor at the given index, as used by [`MonitorSelection::Number`].
+    pub fn get(&self, index: usize) -> Option<&Monitor> {
+        self.monitors.get(index)
+    }
+
+    #[allow(missing_docs)]
+    pub fn update_from_backend(&mut self, monitors: Vec<Monitor>) {
+        self.monitors = monitors;
+    }
 }
 
 impl Window {
@@ -127,15 +232,25 @@ impl Window {
             id,
             requested_width: window_descriptor.width,
             requested_height: window_descriptor.height,
+            requested_min_width: window_descriptor.min_width,
+            requested_min_height: window_descriptor.min_height,
+            requested_max_width: window_descriptor.max_width,
+            requested_max_height: window_descriptor.max_height,
             physical_width,
             physical_height,
+            physical_position: match window_descriptor.position {
+                WindowPosition::At(position) => Some(position),
+                WindowPosition::Automatic | WindowPosition::Centered(_) => None,
+            },
             scale_factor,
+            scale_factor_override: window_descriptor.scale_factor_override,
             title: window_descriptor.title.clone(),
             vsync: window_descriptor.vsync,
             resizable: window_descriptor.resizable,
             decorations: window_descriptor.decorations,
             cursor_visible: window_descriptor.cursor_visible,
             cursor_locked: window_descriptor.cursor_locked,
+            cursor_icon: window_descriptor.cursor_icon,
             cursor_position: None,
             mode: window_descriptor.mode,
             #[cfg(target_arch = "wasm32")]
@@ -152,13 +267,13 @@ impl Window {
     /// The current logical width of the window's client area.
     #[inline]
     pub fn width(&self) -> f32 {
-        (self.physical_width as f64 / self.scale_factor) as f32
+        (self.physical_width as f64 / self.scale_factor()) as f32
     }
 
     /// The current logical height of the window's client area.
     #[inline]
     pub fn height(&self) -> f32 {
-        (self.physical_height as f64 / self.scale_factor) as f32
+        (self.physical_height as f64 / self.scale_factor()) as f32
     }
 
     /// The requested window client area width in logical pixels from window
@@ -181,6 +296,62 @@ impl Window {
         self.requested_height
     }
 
+    /// The requested minimum window client area width in logical pixels, if
+    /// any, from window creation or the last call to
+    /// [set_min_resolution](Window::set_min_resolution).
+    #[inline]
+    pub fn requested_min_width(&self) -> Option<f32> {
+        self.requested_min_width
+    }
+
+    /// The requested minimum window client area height in logical pixels, if
+    /// any, from window creation or the last call to
+    /// [set_min_resolution](Window::set_min_resolution).
+    #[inline]
+    pub fn requested_min_height(&self) -> Option<f32> {
+        self.requested_min_height
+    }
+
+    /// The requested maximum window client area width in logical pixels, if
+    /// any, from window creation or the last call to
+    /// [set_max_resolution](Window::set_max_resolution).
+    #[inline]
+    pub fn requested_max_width(&self) -> Option<f32> {
+        self.requested_max_width
+    }
+
+    /// The requested maximum window client area height in logical pixels, if
+    /// any, from window creation or the last call to
+    /// [set_max_resolution](Window::set_max_resolution).
+    #[inline]
+    pub fn requested_max_height(&self) -> Option<f32> {
+        self.requested_max_height
+    }
+
+    /// Request the OS to constrain resizing of this window's client area to
+    /// never go below the given width and height. `None` removes that bound
+    /// on the corresponding axis. These are client-area (inner) dimensions,
+    /// not outer-window dimensions, so decorations are not included.
+    pub fn set_min_resolution(&mut self, min_width: Option<f32>, min_height: Option<f32>) {
+        self.requested_min_width = min_width;
+        self.requested_min_height = min_height;
+        self.command_queue.push(WindowCommand::SetMinResolution {
+            min_resolution: (min_width, min_height),
+        });
+    }
+
+    /// Request the OS to constrain resizing of this window's client area to
+    /// never exceed the given width and height. `None` removes that bound on
+    /// the corresponding axis. These are client-area (inner) dimensions, not
+    /// outer-window dimensions, so decorations are not included.
+    pub fn set_max_resolution(&mut self, max_width: Option<f32>, max_height: Option<f32>) {
+        self.requested_max_width = max_width;
+        self.requested_max_height = max_height;
+        self.command_queue.push(WindowCommand::SetMaxResolution {
+            max_resolution: (max_width, max_height),
+        });
+    }
+
     /// The window's client area width in physical pixels.
     #[inline]
     pub fn physical_width(&self) -> u32 {
@@ -193,12 +364,53 @@ impl Window {
         self.physical_height
     }
 
+    /// The window's current position on the desktop, in physical pixels,
+    /// measured from the top-left of the primary monitor. Until the backend
+    /// reports a position, this is the position requested with
+    /// [`WindowPosition::At`] at creation, or `None` for any other
+    /// [`WindowPosition`].
+    #[inline]
+    pub fn position(&self) -> Option<IVec2> {
+        self.physical_position
+    }
+
+    /// Request the OS to move the window such that its top-left corner is at
+    /// the given physical pixel position. Physical pixels are used, rather
+    /// than logical pixels, to avoid the scale-factor ambiguity that can
+    /// place the window off-screen when logical and physical pixels are
+    /// conflated across monitors with differing scale factors.
+    pub fn set_position(&mut self, position: IVec2) {
+        self.command_queue
+            .push(WindowCommand::SetPosition { position });
+    }
+
+    /// Request the OS to center the window on the given monitor.
+    pub fn set_centered(&mut self, monitor: MonitorSelection) {
+        self.command_queue
+            .push(WindowCommand::SetPositionCentered { monitor });
+    }
+
+    #[allow(missing_docs)]
+    #[inline]
+    pub fn update_actual_position_from_backend(&mut self, position: IVec2) {
+        self.physical_position = Some(position);
+    }
+
     #[inline]
     pub fn set_maximized(&mut self, maximized: bool) {
         self.command_queue
             .push(WindowCommand::SetMaximized { maximized });
     }
 
+    /// Request user attention, flashing the taskbar/dock entry or similar,
+    /// depending on the platform and `request_type`. Pass `None` to cancel a
+    /// pending request.
+    pub fn request_user_attention(&mut self, request_type: Option<UserAttentionType>) {
+        self.command_queue.push(WindowCommand::RequestUserAttention {
+            request: request_type,
+        });
+    }
+
     /// Request the OS to resize the window such the the client area matches the
     /// specified width and height.
     pub fn set_resolution(&mut self, width: f32, height: f32) {
@@ -209,10 +421,18 @@ impl Window {
         });
     }
 
-    #[allow(missing_docs)]
+    /// Update this window's backend-reported scale factor, returning the
+    /// previous value.
+    ///
+    /// `Window` only tracks state and queues outgoing commands; it has no
+    /// mechanism of its own for notifying consumers of a change. The caller
+    /// driving the event loop (the windowing backend) is expected to compare
+    /// the returned value against `scale_factor` and emit a
+    /// scale-factor-changed notification through the app's event system when
+    /// they differ, the same way it emits other window lifecycle events.
     #[inline]
-    pub fn update_scale_factor_from_backend(&mut self, scale_factor: f64) {
-        self.scale_factor = scale_factor;
+    pub fn update_scale_factor_from_backend(&mut self, scale_factor: f64) -> f64 {
+        std::mem::replace(&mut self.scale_factor, scale_factor)
     }
 
     #[allow(missing_docs)]
@@ -222,14 +442,41 @@ impl Window {
         self.physical_height = physical_height;
     }
 
-    /// The ratio of physical pixels to logical pixels
+    /// The ratio of physical pixels to logical pixels.
     ///
     /// `physical_pixels = logical_pixels * scale_factor`
+    ///
+    /// Returns [`scale_factor_override`](Window::scale_factor_override) if
+    /// one is set, otherwise the scale factor reported by the backend.
     #[inline]
     pub fn scale_factor(&self) -> f64 {
+        self.scale_factor_override.unwrap_or(self.scale_factor)
+    }
+
+    /// The ratio of physical pixels to logical pixels as reported by the
+    /// backend, ignoring any [`scale_factor_override`](Window::scale_factor_override).
+    #[inline]
+    pub fn backend_scale_factor(&self) -> f64 {
         self.scale_factor
     }
 
+    /// The forced scale factor set by [set_scale_factor_override](Window::set_scale_factor_override), if any.
+    #[inline]
+    pub fn scale_factor_override(&self) -> Option<f64> {
+        self.scale_factor_override
+    }
+
+    /// Override the window's scale factor, ignoring what the backend
+    /// reports. Pass `None` to defer to the backend again. Useful for
+    /// testing DPI-dependent layout, or for accessibility features that let
+    /// users force a larger UI scale.
+    pub fn set_scale_factor_override(&mut self, scale_factor_override: Option<f64>) {
+        self.scale_factor_override = scale_factor_override;
+        self.command_queue.push(WindowCommand::SetScaleFactor {
+            scale_factor: self.scale_factor(),
+        });
+    }
+
     #[inline]
     pub fn title(&self) -> &str {
         &self.title
@@ -312,6 +559,17 @@ impl Window {
         self.cursor_position = cursor_position;
     }
 
+    #[inline]
+    pub fn cursor_icon(&self) -> CursorIcon {
+        self.cursor_icon
+    }
+
+    pub fn set_cursor_icon(&mut self, icon: CursorIcon) {
+        self.cursor_icon = icon;
+        self.command_queue
+            .push(WindowCommand::SetCursorIcon { icon });
+    }
+
     #[inline]
     pub fn mode(&self) -> WindowMode {
         self.mode
@@ -331,17 +589,104 @@ impl Window {
     }
 }
 
+/// The icon displayed for the mouse cursor while it is over a window.
+///
+/// These mirror the cursor shapes available across the common desktop
+/// platforms; not every variant is guaranteed to have a distinct appearance
+/// on every platform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CursorIcon {
+    Default,
+    Crosshair,
+    Hand,
+    Arrow,
+    Move,
+    Text,
+    Wait,
+    Help,
+    Progress,
+    NotAllowed,
+    ContextMenu,
+    Cell,
+    VerticalText,
+    Alias,
+    Copy,
+    NoDrop,
+    Grab,
+    Grabbing,
+    AllScroll,
+    ZoomIn,
+    ZoomOut,
+    EResize,
+    NResize,
+    NeResize,
+    NwResize,
+    SResize,
+    SeResize,
+    SwResize,
+    WResize,
+    EwResize,
+    NsResize,
+    NeswResize,
+    NwseResize,
+    ColResize,
+    RowResize,
+}
+
+/// The degree of attention a window should request from the user, e.g. by
+/// flashing the taskbar entry or bouncing the dock icon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UserAttentionType {
+    /// Indicates a critical event that needs immediate attention, such as an
+    /// invalid input, and typically requires the user's response before the
+    /// window can proceed.
+    Critical,
+    /// Indicates a less important event, such as a notification that some
+    /// task has finished, that doesn't require immediate attention.
+    Informational,
+}
+
+/// Where a window should be placed on the desktop when it is first created.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WindowPosition {
+    /// Let the operating system choose the position.
+    Automatic,
+    /// Center the window on the given monitor.
+    Centered(MonitorSelection),
+    /// Place the window's top-left corner at the given physical pixel
+    /// position.
+    At(IVec2),
+}
+
 #[derive(Debug, Clone)]
 pub struct WindowDescriptor {
     pub width: f32,
     pub height: f32,
+    /// The minimum width the window's client area is allowed to be resized
+    /// to, in logical pixels. `None` means no minimum is enforced.
+    pub min_width: Option<f32>,
+    /// The minimum height the window's client area is allowed to be resized
+    /// to, in logical pixels. `None` means no minimum is enforced.
+    pub min_height: Option<f32>,
+    /// The maximum width the window's client area is allowed to be resized
+    /// to, in logical pixels. `None` means no maximum is enforced.
+    pub max_width: Option<f32>,
+    /// The maximum height the window's client area is allowed to be resized
+    /// to, in logical pixels. `None` means no maximum is enforced.
+    pub max_height: Option<f32>,
+    /// Where the window should be placed on the desktop when it is created.
+    pub position: WindowPosition,
     pub title: String,
     pub vsync: bool,
     pub resizable: bool,
     pub decorations: bool,
     pub cursor_visible: bool,
     pub cursor_locked: bool,
+    pub cursor_icon: CursorIcon,
     pub mode: WindowMode,
+    /// Forces a specific scale factor instead of the one reported by the
+    /// backend. `None` defers to the backend's reported scale factor.
+    pub scale_factor_override: Option<f64>,
     #[cfg(target_arch = "wasm32")]
     pub canvas: Option<String>,
 }
@@ -352,12 +697,19 @@ impl Default for WindowDescriptor {
             title: "bevy".to_string(),
             width: 1280.,
             height: 720.,
+            min_width: None,
+            min_height: None,
+            max_width: None,
+            max_height: None,
+            position: WindowPosition::Automatic,
             vsync: true,
             resizable: true,
             decorations: true,
             cursor_locked: false,
             cursor_visible: true,
+            cursor_icon: CursorIcon::Default,
             mode: WindowMode::Windowed,
+            scale_factor_override: None,
             #[cfg(target_arch = "wasm32")]
             canvas: None,
         }